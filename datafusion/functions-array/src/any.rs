@@ -17,17 +17,16 @@
 
 //! [`ScalarUDFImpl`] definitions for array_any_value function.
 
-use crate::utils::make_scalar_function;
+use crate::utils::make_scalar_function_with_scalar_support;
+use arrow::compute::take;
+use arrow_array::builder::Int64Builder;
 use arrow_array::cast::AsArray;
-use arrow_array::types::Int64Type;
-use arrow_array::{Array, ArrayRef, GenericListArray, ListArray, OffsetSizeTrait};
+use arrow_array::{Array, ArrayRef, FixedSizeListArray, GenericListArray, OffsetSizeTrait};
 use arrow_schema::DataType;
 use arrow_schema::DataType::{FixedSizeList, LargeList, List};
 use datafusion_common::{exec_err, plan_err, Result};
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
-use std::sync::Arc;
-use datafusion_common::cast::as_list_array;
 
 make_udf_expr_and_func!(
     ArrayAnyValue,
@@ -49,6 +48,16 @@ impl ArrayAnyValue {
             aliases: vec!["list_any_value".to_string()],
         }
     }
+
+    /// The UDF's canonical name, obtainable without constructing an instance.
+    ///
+    /// This should be a `ScalarUDFImpl::static_name()` override once that
+    /// provided method exists upstream (its default would derive a name from
+    /// `type_name::<Self>()`); `datafusion-expr`, where the trait lives, isn't
+    /// part of this checkout, so this is a stand-alone inherent method for now.
+    pub fn static_name() -> &'static str {
+        "array_any_value"
+    }
 }
 
 impl ScalarUDFImpl for ArrayAnyValue {
@@ -56,7 +65,7 @@ impl ScalarUDFImpl for ArrayAnyValue {
         self
     }
     fn name(&self) -> &str {
-        "array_any_value"
+        Self::static_name()
     }
 
     fn signature(&self) -> &Signature {
@@ -75,7 +84,7 @@ impl ScalarUDFImpl for ArrayAnyValue {
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        make_scalar_function(array_any_value_inner)(args)
+        make_scalar_function_with_scalar_support(array_any_value_inner)(args)
     }
 
     fn aliases(&self) -> &[String] {
@@ -91,30 +100,136 @@ pub fn array_any_value_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
 
     let array_type = args[0].data_type();
     match array_type {
-        List(_) => general_array_any_value::<i32>(&args[0].as_list::<i32>()),
-        LargeList(_) => general_array_any_value::<i64>(&args[0].as_list::<i64>()),
+        List(_) => general_array_any_value::<i32>(args[0].as_list::<i32>()),
+        LargeList(_) => general_array_any_value::<i64>(args[0].as_list::<i64>()),
+        FixedSizeList(_, _) => fixed_size_array_any_value(args[0].as_fixed_size_list()),
         _ => exec_err!("array_any_value does not support type '{array_type:?}'."),
     }
 }
 
+/// For each row, finds the absolute index (into the child values array) of the
+/// first non-null element and gathers those indices in a single `take` call so
+/// we never materialize the elements row-by-row.
 fn general_array_any_value<O: OffsetSizeTrait>(
     list_array: &GenericListArray<O>,
 ) -> Result<ArrayRef> {
-    let mut data: Vec<Option<Vec<Option<i64>>>> = Vec::with_capacity(list_array.len());
-    for (row_index, list_array_row) in list_array.iter().enumerate() {
-        if let Some(list_array_row) = list_array_row {
-            let list_array_row_inner = as_list_array(list_array_row.as_list::<O>())?;
-            // if let Some(non_empty) = list_array_row_inner.iter().find(|&s| !s.is_empty()) {
-            //     // println!("First non-empty string: {}", non_empty);
-            //     data.push(Some(vec![Some(1)]));
-            // } else {
-            //     println!("All strings are empty.");
-            // }
-        } else {
-            data.push(None);
+    let values = list_array.values();
+    let offsets = list_array.offsets();
+    let mut indices = Int64Builder::with_capacity(list_array.len());
+
+    for row_index in 0..list_array.len() {
+        if list_array.is_null(row_index) {
+            indices.append_null();
+            continue;
+        }
+
+        let start = offsets[row_index].as_usize();
+        let end = offsets[row_index + 1].as_usize();
+        let any_value_index = (start..end).find(|&idx| values.is_valid(idx));
+
+        match any_value_index {
+            Some(idx) => indices.append_value(idx as i64),
+            None => indices.append_null(),
+        }
+    }
+
+    Ok(take(values.as_ref(), &indices.finish(), None)?)
+}
+
+/// Same as [`general_array_any_value`] but for `FixedSizeList`, whose rows
+/// don't have an offsets buffer and instead all share a fixed `value_length`.
+fn fixed_size_array_any_value(list_array: &FixedSizeListArray) -> Result<ArrayRef> {
+    let values = list_array.values();
+    let value_length = list_array.value_length() as usize;
+    let mut indices = Int64Builder::with_capacity(list_array.len());
+
+    for row_index in 0..list_array.len() {
+        if list_array.is_null(row_index) {
+            indices.append_null();
+            continue;
+        }
+
+        let start = row_index * value_length;
+        let end = start + value_length;
+        let any_value_index = (start..end).find(|&idx| values.is_valid(idx));
+
+        match any_value_index {
+            Some(idx) => indices.append_value(idx as i64),
+            None => indices.append_null(),
         }
     }
-    Ok(Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(
-        data,
-    )))
+
+    Ok(take(values.as_ref(), &indices.finish(), None)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Int64Array, ListArray};
+    use arrow_buffer::{NullBuffer, OffsetBuffer};
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    fn int64_list_array(
+        values: Vec<Option<i64>>,
+        offsets: Vec<i32>,
+        row_nulls: Option<Vec<bool>>,
+    ) -> ListArray {
+        let field = Arc::new(Field::new_list_field(DataType::Int64, true));
+        ListArray::new(
+            field,
+            OffsetBuffer::new(offsets.into()),
+            Arc::new(Int64Array::from(values)),
+            row_nulls.map(NullBuffer::from),
+        )
+    }
+
+    #[test]
+    fn skips_leading_nulls_within_a_row() {
+        // row 0: [NULL, NULL, 3]
+        let list = int64_list_array(vec![None, None, Some(3)], vec![0, 3], None);
+        let result = general_array_any_value::<i32>(&list).unwrap();
+        let result = result.as_primitive::<arrow_array::types::Int64Type>();
+        assert_eq!(result.value(0), 3);
+    }
+
+    #[test]
+    fn empty_row_returns_null() {
+        // row 0: []
+        let list = int64_list_array(vec![], vec![0, 0], None);
+        let result = general_array_any_value::<i32>(&list).unwrap();
+        assert!(result.is_null(0));
+    }
+
+    #[test]
+    fn all_null_row_returns_null() {
+        // row 0: [NULL, NULL]
+        let list = int64_list_array(vec![None, None], vec![0, 2], None);
+        let result = general_array_any_value::<i32>(&list).unwrap();
+        assert!(result.is_null(0));
+    }
+
+    #[test]
+    fn null_list_row_returns_null() {
+        // row 0 is a NULL list itself, not an empty/all-null one
+        let list = int64_list_array(vec![Some(1)], vec![0, 1], Some(vec![false]));
+        let result = general_array_any_value::<i32>(&list).unwrap();
+        assert!(result.is_null(0));
+    }
+
+    #[test]
+    fn fixed_size_list_skips_nulls() {
+        let values = Int64Array::from(vec![None, Some(2), Some(3), Some(4)]);
+        let field = Arc::new(Field::new_list_field(DataType::Int64, true));
+        let list = FixedSizeListArray::new(field, 2, Arc::new(values), None);
+        let result = fixed_size_array_any_value(&list).unwrap();
+        let result = result.as_primitive::<arrow_array::types::Int64Type>();
+        assert_eq!(result.value(0), 2);
+        assert_eq!(result.value(1), 3);
+    }
+
+    #[test]
+    fn static_name_matches_name() {
+        assert_eq!(ArrayAnyValue::static_name(), ArrayAnyValue::new().name());
+    }
 }
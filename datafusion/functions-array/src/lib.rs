@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Scalar functions for DataFusion's nested types (`List`, `LargeList`,
+//! `FixedSizeList` and `Map`).
+//!
+//! The `array_expressions` and `map_expressions` features gate the `List`
+//! and `Map` function families independently, so embedders that only need
+//! one surface don't have to link in the other. Both are enabled by
+//! `default`.
+
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "array_expressions")]
+mod any;
+#[cfg(feature = "map_expressions")]
+mod map_keys;
+#[cfg(feature = "map_expressions")]
+mod map_values;
+#[cfg(any(feature = "array_expressions", feature = "map_expressions"))]
+mod utils;
+
+use datafusion_expr::ScalarUDF;
+use std::sync::Arc;
+
+/// Returns all scalar functions defined in this crate, for registration with
+/// a `SessionContext` or `FunctionRegistry`.
+///
+/// Only includes the functions whose feature is enabled, so `SessionContext`
+/// registration stays consistent with what was actually compiled in.
+pub fn functions() -> Vec<Arc<ScalarUDF>> {
+    #[allow(unused_mut)]
+    let mut funcs = Vec::new();
+
+    #[cfg(feature = "array_expressions")]
+    funcs.push(any::array_any_value_udf());
+
+    #[cfg(feature = "map_expressions")]
+    {
+        funcs.push(map_keys::map_keys_udf());
+        funcs.push(map_values::map_values_udf());
+    }
+
+    funcs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts `functions()` includes exactly the UDFs whose feature is
+    /// enabled for *this* build -- runs (and must pass) under every feature
+    /// combination: `--no-default-features --features array_expressions`,
+    /// `--no-default-features --features map_expressions`, `--all-features`,
+    /// and `--no-default-features`.
+    #[test]
+    fn functions_match_enabled_features() {
+        let names: Vec<&str> = functions().iter().map(|f| f.name()).collect();
+
+        assert_eq!(
+            names.contains(&"array_any_value"),
+            cfg!(feature = "array_expressions")
+        );
+        assert_eq!(
+            names.contains(&"map_keys"),
+            cfg!(feature = "map_expressions")
+        );
+        assert_eq!(
+            names.contains(&"map_values"),
+            cfg!(feature = "map_expressions")
+        );
+    }
+}
@@ -0,0 +1,44 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Macros for defining a scalar function alongside its `Expr`-builder helper
+//! and its cached [`ScalarUDF`](datafusion_expr::ScalarUDF) singleton.
+
+/// Creates a singleton `$SCALAR_UDF_FN` function for the `$UDF` struct, plus an
+/// `$EXPR_FN_NAME` free function that builds an `Expr::ScalarFunction` call
+/// from it, so callers never construct the UDF directly.
+macro_rules! make_udf_expr_and_func {
+    ($UDF:ty, $EXPR_FN_NAME:ident, $($arg:ident)*, $DOC:expr , $SCALAR_UDF_FN:ident) => {
+        #[doc = $DOC]
+        pub fn $EXPR_FN_NAME($($arg: datafusion_expr::Expr),*) -> datafusion_expr::Expr {
+            datafusion_expr::Expr::ScalarFunction(
+                datafusion_expr::expr::ScalarFunction::new_udf($SCALAR_UDF_FN(), vec![$($arg),*]),
+            )
+        }
+
+        #[doc = $DOC]
+        pub fn $SCALAR_UDF_FN() -> std::sync::Arc<datafusion_expr::ScalarUDF> {
+            static INSTANCE: std::sync::OnceLock<std::sync::Arc<datafusion_expr::ScalarUDF>> =
+                std::sync::OnceLock::new();
+            INSTANCE
+                .get_or_init(|| {
+                    std::sync::Arc::new(datafusion_expr::ScalarUDF::new_from_impl(<$UDF>::new()))
+                })
+                .clone()
+        }
+    };
+}
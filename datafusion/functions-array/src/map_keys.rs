@@ -0,0 +1,166 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for map_keys function.
+
+use crate::utils::{get_map_entry_field, make_scalar_function_with_scalar_support};
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, ListArray};
+use arrow_schema::{DataType, Field};
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::signature::ArrayFunctionSignature;
+use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, TypeSignature, Volatility};
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    MapKeys,
+    map_keys,
+    map,
+    "returns a list of all keys in the map.",
+    map_keys_udf
+);
+
+#[derive(Debug)]
+pub(super) struct MapKeys {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl MapKeys {
+    pub fn new() -> Self {
+        Self {
+            // `Signature::array()` only coerces to List/LargeList/FixedSizeList;
+            // a Map-accepting function needs the Map-specific array signature.
+            signature: Signature::new(
+                TypeSignature::ArraySignature(ArrayFunctionSignature::MapArray),
+                Volatility::Immutable,
+            ),
+            aliases: vec![],
+        }
+    }
+
+    /// The UDF's canonical name, obtainable without constructing an instance.
+    ///
+    /// This should be a `ScalarUDFImpl::static_name()` override once that
+    /// provided method exists upstream; `datafusion-expr`, where the trait
+    /// lives, isn't part of this checkout, so this is a stand-alone inherent
+    /// method for now. See `ArrayAnyValue::static_name` for the full rationale.
+    pub fn static_name() -> &'static str {
+        "map_keys"
+    }
+}
+
+impl ScalarUDFImpl for MapKeys {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        Self::static_name()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        if arg_types.len() != 1 {
+            return exec_err!("map_keys expects single argument");
+        }
+        let entry_fields = get_map_entry_field(&arg_types[0])?;
+        Ok(DataType::List(Arc::new(Field::new(
+            "item",
+            entry_fields[0].data_type().clone(),
+            entry_fields[0].is_nullable(),
+        ))))
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        make_scalar_function_with_scalar_support(map_keys_inner)(args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
+
+fn map_keys_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 1 {
+        return exec_err!("map_keys expects single argument");
+    }
+
+    // Derive the item field's nullability from the schema, not from whether
+    // this particular batch happens to contain a null key -- otherwise
+    // `invoke()` could disagree batch-to-batch with the type `return_type()`
+    // already promised for the same expression.
+    let entry_fields = get_map_entry_field(args[0].data_type())?;
+    let key_field = Arc::new(Field::new_list_field(
+        entry_fields[0].data_type().clone(),
+        entry_fields[0].is_nullable(),
+    ));
+
+    let map_array = match args[0].data_type() {
+        DataType::Map(_, _) => args[0].as_map(),
+        _ => return exec_err!("map_keys expects a map argument"),
+    };
+
+    Ok(Arc::new(ListArray::new(
+        key_field,
+        map_array.offsets().clone(),
+        Arc::clone(map_array.keys()),
+        map_array.nulls().cloned(),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::string_to_int_map_array;
+    use datafusion_expr::type_coercion::functions::data_types;
+
+    #[test]
+    fn signature_coerces_map_type() {
+        let map_array = string_to_int_map_array();
+        let arg_type = map_array.data_type().clone();
+
+        // Drive the actual planner coercion entrypoint (not `return_type`,
+        // which never looks at `signature()`) so this regresses if the
+        // signature stops matching `DataType::Map` again.
+        let udf = MapKeys::new();
+        let coerced = data_types(&[arg_type.clone()], udf.signature())
+            .expect("map_keys's signature must accept a real Map argument");
+        assert_eq!(coerced, vec![arg_type]);
+    }
+
+    #[test]
+    fn invoke_returns_list_of_keys() {
+        let map_array: ArrayRef = Arc::new(string_to_int_map_array());
+        let result = map_keys_inner(&[map_array]).unwrap();
+        let result = result.as_list::<i32>();
+
+        let row0: Vec<_> = result.value(0).as_string::<i32>().iter().collect();
+        let row1: Vec<_> = result.value(1).as_string::<i32>().iter().collect();
+        assert_eq!(row0, vec![Some("a"), Some("b")]);
+        assert_eq!(row1, vec![Some("c")]);
+    }
+
+    #[test]
+    fn static_name_matches_name() {
+        assert_eq!(MapKeys::static_name(), MapKeys::new().name());
+    }
+}
@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Utility functions shared by the nested-type scalar functions in this crate.
+
+use arrow_array::ArrayRef;
+use arrow_schema::{DataType, Fields};
+use datafusion_common::{exec_err, Result, ScalarValue};
+use datafusion_expr::ColumnarValue;
+
+/// Wraps a kernel that operates on fully-materialized [`ArrayRef`] arguments so
+/// it can be used as the `invoke` implementation of a [`datafusion_expr::ScalarUDFImpl`].
+///
+/// `ColumnarValue::values_to_arrays` already turns a lone scalar argument into
+/// a length-1 array (it only broadcasts to N when another argument is a
+/// length-N array), so the only thing this adds over calling the kernel
+/// directly is unwrapping a single-row result back into `ColumnarValue::Scalar`
+/// instead of always returning `ColumnarValue::Array`.
+pub(crate) fn make_scalar_function_with_scalar_support<F>(
+    inner: F,
+) -> impl Fn(&[ColumnarValue]) -> Result<ColumnarValue>
+where
+    F: Fn(&[ArrayRef]) -> Result<ArrayRef>,
+{
+    move |args: &[ColumnarValue]| {
+        let all_scalar = args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+        let args = ColumnarValue::values_to_arrays(args)?;
+        let result = (inner)(&args)?;
+
+        if all_scalar {
+            ScalarValue::try_from_array(&result, 0).map(ColumnarValue::Scalar)
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+}
+
+/// Returns the `key`/`value` [`Fields`] backing a `DataType::Map`, i.e. the
+/// fields of the `Struct` entries type a `Map` is physically a `List` of.
+pub(crate) fn get_map_entry_field(data_type: &DataType) -> Result<&Fields> {
+    match data_type {
+        DataType::Map(field, _) => match field.data_type() {
+            DataType::Struct(fields) => Ok(fields),
+            _ => exec_err!(
+                "Expected a Map's entries to be a Struct, got {:?}",
+                field.data_type()
+            ),
+        },
+        _ => exec_err!("The argument for map function should be a map, got {data_type:?}"),
+    }
+}
+
+/// Test fixtures shared by `map_keys`/`map_values` (and anything else in this
+/// crate that needs a `Map` array to exercise).
+#[cfg(test)]
+pub(crate) mod test_util {
+    use arrow_array::{ArrayRef, Int64Array, MapArray, StringArray, StructArray};
+    use arrow_buffer::OffsetBuffer;
+    use arrow_schema::{DataType, Field};
+    use std::sync::Arc;
+
+    /// A 2-row `Map<Utf8, Int64>` array: `{a: 1, b: 2}`, `{c: 3}`.
+    pub(crate) fn string_to_int_map_array() -> MapArray {
+        let entries = StructArray::from(vec![
+            (
+                Arc::new(Field::new("key", DataType::Utf8, false)),
+                Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("value", DataType::Int64, true)),
+                Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef,
+            ),
+        ]);
+        let field = Arc::new(Field::new("entries", entries.data_type().clone(), false));
+        MapArray::new(
+            field,
+            OffsetBuffer::new(vec![0, 2, 3].into()),
+            entries,
+            None,
+            false,
+        )
+    }
+}